@@ -26,7 +26,7 @@ fn main() {
                 println!("Response: {:?}", choice.message.content);
             }
         }
-        Err(e) => println!("Denied: {:?} — {}", e.reason_code, e.message),
+        Err(e) => println!("Denied: {:?} — {}", e.reason_code(), e),
     }
 
     // 2. Conformance
@@ -39,7 +39,7 @@ fn main() {
             "Verdict: {} Gates: {} Failed: {}",
             conf.verdict, conf.gates, conf.failed
         ),
-        Err(e) => println!("Conformance error: {:?}", e.reason_code),
+        Err(e) => println!("Conformance error: {:?}", e.reason_code()),
     }
 
     // 3. Health
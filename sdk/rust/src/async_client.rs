@@ -0,0 +1,370 @@
+// HELM SDK — async client (feature = "async")
+//
+// Mirrors the full method surface of `HelmClient` but is built on
+// `reqwest::Client` (async) + `tokio`, so it can be awaited from inside
+// an async runtime instead of blocking a thread per call. Shares the
+// `types_gen` structs and the same error-mapping shape as the blocking
+// client.
+
+use reqwest::Client;
+use std::sync::Mutex;
+use std::time::Duration;
+
+use crate::retry;
+use crate::{
+    ApprovalRequest, AsyncChatCompletionStream, AuthConfig, Capability, ChatCompletionRequest,
+    ChatCompletionResponse, ConformanceRequest, ConformanceResult, HelmApiError, HelmError,
+    HelmSigner, Negotiated, ReasonCode, Receipt, RetryPolicy, Session, VerificationResult,
+    VersionInfo,
+};
+
+/// Async counterpart to [`crate::HelmClient`].
+pub struct AsyncHelmClient {
+    base_url: String,
+    client: Client,
+    auth: AuthConfig,
+    retry_policy: RetryPolicy,
+    negotiated: Mutex<Option<Negotiated>>,
+}
+
+impl AsyncHelmClient {
+    /// Create a new, unauthenticated async client.
+    pub fn new(base_url: &str) -> Self {
+        Self::with_auth(base_url, AuthConfig::none())
+    }
+
+    /// Create a new async client that attaches credentials from `auth` to
+    /// every request, refreshing and retrying once on a `401`.
+    pub fn with_auth(base_url: &str, auth: AuthConfig) -> Self {
+        Self {
+            base_url: base_url.trim_end_matches('/').to_string(),
+            client: Client::builder()
+                .timeout(Duration::from_secs(30))
+                .build()
+                .expect("failed to build HTTP client"),
+            auth,
+            retry_policy: RetryPolicy::none(),
+            negotiated: Mutex::new(None),
+        }
+    }
+
+    /// Opt in to retrying retryable failures ([`HelmApiError::is_retryable`])
+    /// under `policy`.
+    pub fn with_retry_policy(mut self, policy: RetryPolicy) -> Self {
+        self.retry_policy = policy;
+        self
+    }
+
+    /// Negotiate protocol capabilities with the kernel, caching the result
+    /// for subsequent calls.
+    pub async fn negotiate(&self) -> Result<Negotiated, HelmApiError> {
+        if let Some(n) = self.negotiated.lock().unwrap().clone() {
+            return Ok(n);
+        }
+        let info = self.version().await?;
+        let negotiated = Negotiated::from_version_info(info)?;
+        *self.negotiated.lock().unwrap() = Some(negotiated.clone());
+        Ok(negotiated)
+    }
+
+    fn url(&self, path: &str) -> String {
+        format!("{}{}", self.base_url, path)
+    }
+
+    /// Build, send, and check a request via `build`: attaches the
+    /// configured auth header and (if `idempotency_key` is set) an
+    /// `Idempotency-Key` header reused across every attempt. Refreshes
+    /// auth and retries once on a `401`, and retries retryable failures
+    /// per the configured [`RetryPolicy`] with backoff between attempts.
+    async fn request(
+        &self,
+        idempotency_key: Option<String>,
+        build: impl Fn(&Client) -> reqwest::RequestBuilder,
+    ) -> Result<reqwest::Response, HelmApiError> {
+        let mut attempt = 0u32;
+        let mut refreshed_once = false;
+        loop {
+            let outcome = match self.send_once(idempotency_key.as_deref(), &build).await {
+                Ok(resp) => self.check(resp).await,
+                Err(e) => Err(e),
+            };
+            match outcome {
+                Ok(resp) => return Ok(resp),
+                Err(HelmApiError::Api { status: 401, .. }) if !refreshed_once => {
+                    refreshed_once = true;
+                    self.auth.invalidate();
+                }
+                Err(e) if e.is_retryable() && attempt < self.retry_policy.max_retries() => {
+                    tokio::time::sleep(self.retry_policy.delay_for_attempt(attempt)).await;
+                    attempt += 1;
+                }
+                Err(e) => return Err(e),
+            }
+        }
+    }
+
+    async fn send_once(
+        &self,
+        idempotency_key: Option<&str>,
+        build: &impl Fn(&Client) -> reqwest::RequestBuilder,
+    ) -> Result<reqwest::Response, HelmApiError> {
+        let mut builder = build(&self.client);
+        if let Some(key) = idempotency_key {
+            builder = builder.header("Idempotency-Key", key);
+        }
+        if let Some(token) = self.auth.token_async(&self.client).await? {
+            builder = builder.bearer_auth(token.expose_secret());
+        }
+        builder
+            .send()
+            .await
+            .map_err(|e| HelmApiError::Transport(e.to_string()))
+    }
+
+    async fn check(&self, resp: reqwest::Response) -> Result<reqwest::Response, HelmApiError> {
+        if resp.status().is_success() {
+            return Ok(resp);
+        }
+        let status = resp.status().as_u16();
+        match resp.json::<HelmError>().await {
+            Ok(e) => Err(HelmApiError::Api {
+                status,
+                message: e.error.message,
+                reason_code: e.error.reason_code,
+                details: e.error.details,
+            }),
+            Err(_) => Err(HelmApiError::Api {
+                status,
+                message: "unknown error".into(),
+                reason_code: ReasonCode::ErrorInternal,
+                details: None,
+            }),
+        }
+    }
+
+    /// POST /v1/chat/completions
+    pub async fn chat_completions(
+        &self,
+        req: &ChatCompletionRequest,
+    ) -> Result<ChatCompletionResponse, HelmApiError> {
+        let key = retry::generate_idempotency_key();
+        let resp = self
+            .request(Some(key), |c| {
+                c.post(self.url("/v1/chat/completions")).json(req)
+            })
+            .await?;
+        resp.json()
+            .await
+            .map_err(|e| HelmApiError::Decode(e.to_string()))
+    }
+
+    /// POST /v1/chat/completions with `stream: true` — returns an async
+    /// stream of incremental [`crate::ChatCompletionChunk`] deltas instead
+    /// of a single full response.
+    pub async fn chat_completions_stream(
+        &self,
+        req: &ChatCompletionRequest,
+    ) -> Result<AsyncChatCompletionStream, HelmApiError> {
+        self.negotiate().await?.require(Capability::Streaming)?;
+        let mut streamed = req.clone();
+        streamed.stream = Some(true);
+        let resp = self
+            .request(None, |c| {
+                c.post(self.url("/v1/chat/completions")).json(&streamed)
+            })
+            .await?;
+        Ok(AsyncChatCompletionStream::new(resp))
+    }
+
+    /// POST /api/v1/kernel/approve
+    ///
+    /// If a retried submission comes back as `DENY_IDEMPOTENCY_DUPLICATE`
+    /// and the kernel included the original `receipt_id` in the denial
+    /// details, fetches and returns that receipt instead of surfacing the
+    /// duplicate as a new failure.
+    pub async fn approve_intent(&self, req: &ApprovalRequest) -> Result<Receipt, HelmApiError> {
+        let key = retry::generate_idempotency_key();
+        match self
+            .request(Some(key), |c| {
+                c.post(self.url("/api/v1/kernel/approve")).json(req)
+            })
+            .await
+        {
+            Ok(resp) => resp
+                .json()
+                .await
+                .map_err(|e| HelmApiError::Decode(e.to_string())),
+            Err(HelmApiError::Api {
+                reason_code: ReasonCode::DenyIdempotencyDuplicate,
+                details: Some(details),
+                ..
+            }) if details.get("receipt_id").and_then(|v| v.as_str()).is_some() => {
+                self.get_receipt(details["receipt_id"].as_str().unwrap())
+                    .await
+            }
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Sign `intent_hash` with `signer` and submit it to
+    /// /api/v1/kernel/approve in one call.
+    pub async fn approve_intent_signed(
+        &self,
+        intent_hash: &str,
+        signer: &HelmSigner,
+    ) -> Result<Receipt, HelmApiError> {
+        let req = signer
+            .sign_intent(intent_hash, None)
+            .map_err(|e| HelmApiError::Decode(e.to_string()))?;
+        self.approve_intent(&req).await
+    }
+
+    /// GET /api/v1/proofgraph/sessions
+    pub async fn list_sessions(&self) -> Result<Vec<Session>, HelmApiError> {
+        let resp = self
+            .request(None, |c| c.get(self.url("/api/v1/proofgraph/sessions")))
+            .await?;
+        resp.json()
+            .await
+            .map_err(|e| HelmApiError::Decode(e.to_string()))
+    }
+
+    /// GET /api/v1/proofgraph/sessions/{id}/receipts
+    pub async fn get_receipts(&self, session_id: &str) -> Result<Vec<Receipt>, HelmApiError> {
+        let resp = self
+            .request(None, |c| {
+                c.get(self.url(&format!(
+                    "/api/v1/proofgraph/sessions/{}/receipts",
+                    session_id
+                )))
+            })
+            .await?;
+        resp.json()
+            .await
+            .map_err(|e| HelmApiError::Decode(e.to_string()))
+    }
+
+    /// GET /api/v1/proofgraph/receipts/{hash}
+    pub async fn get_receipt(&self, receipt_hash: &str) -> Result<Receipt, HelmApiError> {
+        let resp = self
+            .request(None, |c| {
+                c.get(self.url(&format!("/api/v1/proofgraph/receipts/{}", receipt_hash)))
+            })
+            .await?;
+        resp.json()
+            .await
+            .map_err(|e| HelmApiError::Decode(e.to_string()))
+    }
+
+    /// POST /api/v1/evidence/export — returns raw bytes
+    pub async fn export_evidence(&self, session_id: Option<&str>) -> Result<Vec<u8>, HelmApiError> {
+        let resp = self
+            .request(None, |c| {
+                let body = serde_json::json!({
+                    "session_id": session_id,
+                    "format": "tar.gz"
+                });
+                c.post(self.url("/api/v1/evidence/export")).json(&body)
+            })
+            .await?;
+        resp.bytes()
+            .await
+            .map(|b| b.to_vec())
+            .map_err(|e| HelmApiError::Decode(e.to_string()))
+    }
+
+    /// POST /api/v1/evidence/verify
+    pub async fn verify_evidence(&self, bundle: &[u8]) -> Result<VerificationResult, HelmApiError> {
+        let resp = self
+            .request(None, |c| {
+                let form = reqwest::multipart::Form::new().part(
+                    "bundle",
+                    reqwest::multipart::Part::bytes(bundle.to_vec())
+                        .file_name("pack.tar.gz")
+                        .mime_str("application/octet-stream")
+                        .unwrap(),
+                );
+                c.post(self.url("/api/v1/evidence/verify")).multipart(form)
+            })
+            .await?;
+        resp.json()
+            .await
+            .map_err(|e| HelmApiError::Decode(e.to_string()))
+    }
+
+    /// POST /api/v1/replay/verify
+    pub async fn replay_verify(&self, bundle: &[u8]) -> Result<VerificationResult, HelmApiError> {
+        let resp = self
+            .request(None, |c| {
+                let form = reqwest::multipart::Form::new().part(
+                    "bundle",
+                    reqwest::multipart::Part::bytes(bundle.to_vec())
+                        .file_name("pack.tar.gz")
+                        .mime_str("application/octet-stream")
+                        .unwrap(),
+                );
+                c.post(self.url("/api/v1/replay/verify")).multipart(form)
+            })
+            .await?;
+        resp.json()
+            .await
+            .map_err(|e| HelmApiError::Decode(e.to_string()))
+    }
+
+    /// POST /api/v1/conformance/run
+    pub async fn conformance_run(
+        &self,
+        req: &ConformanceRequest,
+    ) -> Result<ConformanceResult, HelmApiError> {
+        let key = retry::generate_idempotency_key();
+        let resp = self
+            .request(Some(key), |c| {
+                c.post(self.url("/api/v1/conformance/run")).json(req)
+            })
+            .await?;
+        resp.json()
+            .await
+            .map_err(|e| HelmApiError::Decode(e.to_string()))
+    }
+
+    /// GET /api/v1/conformance/reports/{id}
+    pub async fn get_conformance_report(
+        &self,
+        report_id: &str,
+    ) -> Result<ConformanceResult, HelmApiError> {
+        let resp = self
+            .request(None, |c| {
+                c.get(self.url(&format!("/api/v1/conformance/reports/{}", report_id)))
+            })
+            .await?;
+        resp.json()
+            .await
+            .map_err(|e| HelmApiError::Decode(e.to_string()))
+    }
+
+    /// GET /healthz
+    pub async fn health(&self) -> Result<serde_json::Value, HelmApiError> {
+        let resp = self.request(None, |c| c.get(self.url("/healthz"))).await?;
+        resp.json()
+            .await
+            .map_err(|e| HelmApiError::Decode(e.to_string()))
+    }
+
+    /// GET /version
+    pub async fn version(&self) -> Result<VersionInfo, HelmApiError> {
+        let resp = self.request(None, |c| c.get(self.url("/version"))).await?;
+        resp.json()
+            .await
+            .map_err(|e| HelmApiError::Decode(e.to_string()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_async_client_creation() {
+        let _client = AsyncHelmClient::new("http://localhost:8080");
+    }
+}
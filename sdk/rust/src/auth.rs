@@ -0,0 +1,253 @@
+// HELM SDK — authentication: static bearer tokens and OIDC client-credentials.
+//
+// `HelmClient::with_auth` accepts an `AuthConfig` describing how to attach
+// credentials to every request. Bearer mode just injects a static header.
+// OIDC mode fetches an access token lazily, caches it alongside its
+// `expires_in`, and re-fetches once the cache is within `REFRESH_SKEW` of
+// expiring. Secrets are held in a zeroizing wrapper so tokens aren't left
+// behind in memory after drop.
+
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+
+use serde::Deserialize;
+use zeroize::Zeroize;
+
+use crate::{HelmApiError, ReasonCode};
+
+/// Re-fetch an OIDC token once the cached one is within this long of expiring.
+const REFRESH_SKEW: Duration = Duration::from_secs(30);
+
+/// A secret value that is zeroized on drop so tokens aren't left in memory.
+pub struct SecretString(String);
+
+impl SecretString {
+    pub fn new(value: impl Into<String>) -> Self {
+        Self(value.into())
+    }
+
+    /// Access the underlying secret. Named `expose_*` to make call sites
+    /// that read it grep-able.
+    pub fn expose_secret(&self) -> &str {
+        &self.0
+    }
+}
+
+impl Clone for SecretString {
+    fn clone(&self) -> Self {
+        Self(self.0.clone())
+    }
+}
+
+impl std::fmt::Debug for SecretString {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("SecretString(***)")
+    }
+}
+
+impl Drop for SecretString {
+    fn drop(&mut self) {
+        self.0.zeroize();
+    }
+}
+
+/// OIDC client-credentials configuration.
+pub struct OidcClientCredentials {
+    pub token_url: String,
+    pub client_id: String,
+    pub client_secret: SecretString,
+    pub scope: Option<String>,
+}
+
+enum AuthMode {
+    None,
+    Bearer(SecretString),
+    Oidc(OidcClientCredentials),
+}
+
+struct CachedToken {
+    access_token: SecretString,
+    expires_at: Instant,
+}
+
+/// Authentication configuration for [`crate::HelmClient::with_auth`] /
+/// [`crate::async_client::AsyncHelmClient::with_auth`].
+pub struct AuthConfig {
+    mode: AuthMode,
+    cached: Mutex<Option<CachedToken>>,
+}
+
+#[derive(Deserialize)]
+struct TokenResponse {
+    access_token: String,
+    expires_in: Option<u64>,
+}
+
+impl AuthConfig {
+    /// No authentication — requests go out unauthenticated (the current
+    /// default behavior).
+    pub fn none() -> Self {
+        Self {
+            mode: AuthMode::None,
+            cached: Mutex::new(None),
+        }
+    }
+
+    /// Attach a static bearer token to every request.
+    pub fn bearer(token: impl Into<String>) -> Self {
+        Self {
+            mode: AuthMode::Bearer(SecretString::new(token)),
+            cached: Mutex::new(None),
+        }
+    }
+
+    /// Fetch and cache access tokens via the OIDC client-credentials grant.
+    pub fn oidc_client_credentials(
+        token_url: impl Into<String>,
+        client_id: impl Into<String>,
+        client_secret: impl Into<String>,
+        scope: Option<String>,
+    ) -> Self {
+        Self {
+            mode: AuthMode::Oidc(OidcClientCredentials {
+                token_url: token_url.into(),
+                client_id: client_id.into(),
+                client_secret: SecretString::new(client_secret),
+                scope,
+            }),
+            cached: Mutex::new(None),
+        }
+    }
+
+    /// Drop the cached token, forcing the next request to re-fetch one.
+    /// Used on a `401` to force a fresh token before the one retry.
+    pub fn invalidate(&self) {
+        *self.cached.lock().unwrap() = None;
+    }
+
+    fn cached_token(&self) -> Option<SecretString> {
+        let cache = self.cached.lock().unwrap();
+        cache
+            .as_ref()
+            .filter(|t| t.expires_at > Instant::now() + REFRESH_SKEW)
+            .map(|t| t.access_token.clone())
+    }
+
+    fn store_token(&self, access_token: SecretString, expires_in_secs: u64) {
+        *self.cached.lock().unwrap() = Some(CachedToken {
+            access_token,
+            expires_at: Instant::now() + Duration::from_secs(expires_in_secs),
+        });
+    }
+
+    fn oidc_form(oidc: &OidcClientCredentials) -> Vec<(&str, &str)> {
+        let mut form = vec![
+            ("grant_type", "client_credentials"),
+            ("client_id", oidc.client_id.as_str()),
+            ("client_secret", oidc.client_secret.expose_secret()),
+        ];
+        if let Some(scope) = &oidc.scope {
+            form.push(("scope", scope.as_str()));
+        }
+        form
+    }
+
+    fn token_status_error(status: u16) -> HelmApiError {
+        HelmApiError::Api {
+            status,
+            message: format!("token endpoint returned {}", status),
+            reason_code: ReasonCode::ErrorInternal,
+            details: None,
+        }
+    }
+
+    /// Resolve the bearer token to send with the next request, fetching
+    /// and caching an OIDC token if needed. Returns `None` for `AuthMode::None`.
+    #[cfg(feature = "blocking")]
+    pub(crate) fn token_blocking(
+        &self,
+        http: &reqwest::blocking::Client,
+    ) -> Result<Option<SecretString>, HelmApiError> {
+        match &self.mode {
+            AuthMode::None => Ok(None),
+            AuthMode::Bearer(token) => Ok(Some(token.clone())),
+            AuthMode::Oidc(oidc) => {
+                if let Some(token) = self.cached_token() {
+                    return Ok(Some(token));
+                }
+                let resp = http
+                    .post(&oidc.token_url)
+                    .form(&Self::oidc_form(oidc))
+                    .send()
+                    .map_err(|e| HelmApiError::Transport(e.to_string()))?;
+                if !resp.status().is_success() {
+                    return Err(Self::token_status_error(resp.status().as_u16()));
+                }
+                let parsed: TokenResponse = resp
+                    .json()
+                    .map_err(|e| HelmApiError::Decode(e.to_string()))?;
+                let token = SecretString::new(parsed.access_token);
+                self.store_token(token.clone(), parsed.expires_in.unwrap_or(3600));
+                Ok(Some(token))
+            }
+        }
+    }
+
+    /// Async counterpart to [`AuthConfig::token_blocking`].
+    #[cfg(feature = "async")]
+    pub(crate) async fn token_async(
+        &self,
+        http: &reqwest::Client,
+    ) -> Result<Option<SecretString>, HelmApiError> {
+        match &self.mode {
+            AuthMode::None => Ok(None),
+            AuthMode::Bearer(token) => Ok(Some(token.clone())),
+            AuthMode::Oidc(oidc) => {
+                if let Some(token) = self.cached_token() {
+                    return Ok(Some(token));
+                }
+                let resp = http
+                    .post(&oidc.token_url)
+                    .form(&Self::oidc_form(oidc))
+                    .send()
+                    .await
+                    .map_err(|e| HelmApiError::Transport(e.to_string()))?;
+                if !resp.status().is_success() {
+                    return Err(Self::token_status_error(resp.status().as_u16()));
+                }
+                let parsed: TokenResponse = resp
+                    .json()
+                    .await
+                    .map_err(|e| HelmApiError::Decode(e.to_string()))?;
+                let token = SecretString::new(parsed.access_token);
+                self.store_token(token.clone(), parsed.expires_in.unwrap_or(3600));
+                Ok(Some(token))
+            }
+        }
+    }
+}
+
+impl Default for AuthConfig {
+    fn default() -> Self {
+        Self::none()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_secret_string_debug_redacts() {
+        let secret = SecretString::new("super-secret-token");
+        assert_eq!(format!("{:?}", secret), "SecretString(***)");
+    }
+
+    #[test]
+    fn test_cached_token_expires() {
+        let auth = AuthConfig::oidc_client_credentials("http://token", "id", "secret", None);
+        auth.store_token(SecretString::new("tok"), 0);
+        // Already-expired tokens (expires_in = 0) must not be returned from cache.
+        assert!(auth.cached_token().is_none());
+    }
+}
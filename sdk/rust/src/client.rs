@@ -4,5 +4,8 @@
 // The primary client code lives in lib.rs (crate root) for idiomatic Rust.
 
 pub use crate::types_gen::*;
+#[cfg(feature = "async")]
+pub use crate::AsyncHelmClient;
 pub use crate::HelmApiError;
+#[cfg(feature = "blocking")]
 pub use crate::HelmClient;
@@ -0,0 +1,195 @@
+// HELM SDK — typed access to exported evidence bundles.
+//
+// `export_evidence` returns an opaque `tar.gz` blob. `EvidenceBundle`
+// unpacks it into the manifest plus its receipts and blobs so callers can
+// build audit tooling on top of the SDK instead of shelling out to `tar`.
+
+use std::collections::HashMap;
+use std::io::Read;
+
+use ed25519_dalek::VerifyingKey;
+use flate2::read::GzDecoder;
+
+use crate::{verify_chain, EvidenceManifest, ManifestSession, Receipt, VerificationResult};
+
+/// Error unpacking an exported evidence bundle.
+#[derive(Debug)]
+pub enum EvidenceBundleError {
+    Io(std::io::Error),
+    MissingManifest,
+    Decode {
+        path: String,
+        source: serde_json::Error,
+    },
+}
+
+impl std::fmt::Display for EvidenceBundleError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            EvidenceBundleError::Io(e) => write!(f, "failed to read bundle archive: {}", e),
+            EvidenceBundleError::MissingManifest => {
+                write!(f, "bundle archive is missing manifest.json")
+            }
+            EvidenceBundleError::Decode { path, source } => {
+                write!(f, "failed to decode {}: {}", path, source)
+            }
+        }
+    }
+}
+
+impl std::error::Error for EvidenceBundleError {}
+
+impl From<std::io::Error> for EvidenceBundleError {
+    fn from(e: std::io::Error) -> Self {
+        EvidenceBundleError::Io(e)
+    }
+}
+
+/// A parsed `export_evidence` bundle: the manifest plus its receipts and
+/// blobs, keyed the way the archive laid them out.
+pub struct EvidenceBundle {
+    pub manifest: EvidenceManifest,
+    pub receipts: Vec<Receipt>,
+    pub blobs: HashMap<String, Vec<u8>>,
+}
+
+impl EvidenceBundle {
+    /// Decompress and unpack a `tar.gz` evidence bundle as returned by
+    /// `export_evidence`.
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, EvidenceBundleError> {
+        let decoder = GzDecoder::new(bytes);
+        let mut archive = tar::Archive::new(decoder);
+
+        let mut manifest: Option<EvidenceManifest> = None;
+        let mut receipts = Vec::new();
+        let mut blobs = HashMap::new();
+
+        for entry in archive.entries()? {
+            let mut entry = entry?;
+            let path = entry.path()?.to_string_lossy().to_string();
+            let mut contents = Vec::new();
+            entry.read_to_end(&mut contents)?;
+
+            if path == "manifest.json" {
+                manifest = Some(serde_json::from_slice(&contents).map_err(|source| {
+                    EvidenceBundleError::Decode {
+                        path: path.clone(),
+                        source,
+                    }
+                })?);
+            } else if let Some(rest) = path.strip_prefix("receipts/") {
+                let _ = rest;
+                let receipt: Receipt = serde_json::from_slice(&contents).map_err(|source| {
+                    EvidenceBundleError::Decode {
+                        path: path.clone(),
+                        source,
+                    }
+                })?;
+                receipts.push(receipt);
+            } else if let Some(blob_hash) = path.strip_prefix("blobs/") {
+                blobs.insert(blob_hash.to_string(), contents);
+            }
+        }
+
+        Ok(Self {
+            manifest: manifest.ok_or(EvidenceBundleError::MissingManifest)?,
+            receipts,
+            blobs,
+        })
+    }
+
+    /// Sessions recorded in the manifest.
+    pub fn sessions(&self) -> &[ManifestSession] {
+        &self.manifest.sessions
+    }
+
+    /// Receipts belonging to `session_id`, in manifest order.
+    pub fn receipts_for(&self, session_id: &str) -> Vec<&Receipt> {
+        let Some(session) = self
+            .manifest
+            .sessions
+            .iter()
+            .find(|s| s.session_id == session_id)
+        else {
+            return Vec::new();
+        };
+        session
+            .receipt_ids
+            .iter()
+            .filter_map(|id| self.receipts.iter().find(|r| &r.receipt_id == id))
+            .collect()
+    }
+
+    /// Validate this bundle's receipt chain offline via [`verify_chain`].
+    pub fn verify(
+        &self,
+        signing_keys: &std::collections::HashMap<String, VerifyingKey>,
+    ) -> VerificationResult {
+        verify_chain(&self.receipts, signing_keys)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use flate2::{write::GzEncoder, Compression};
+    use std::io::Write;
+
+    fn build_bundle() -> Vec<u8> {
+        let manifest = EvidenceManifest {
+            format: "tar.gz".to_string(),
+            exported_at: "2026-01-01T00:00:00Z".to_string(),
+            sessions: vec![ManifestSession {
+                session_id: "s1".to_string(),
+                receipt_ids: vec!["r1".to_string()],
+            }],
+        };
+        let receipt = Receipt {
+            receipt_id: "r1".to_string(),
+            decision_id: "d1".to_string(),
+            effect_id: "e1".to_string(),
+            status: "ALLOW".to_string(),
+            reason_code: "ALLOW".to_string(),
+            output_hash: "oh".to_string(),
+            blob_hash: "bh".to_string(),
+            prev_hash: "0".repeat(64),
+            lamport_clock: 0,
+            signature: "sig".to_string(),
+            timestamp: "2026-01-01T00:00:00Z".to_string(),
+            principal: "alice".to_string(),
+        };
+
+        let mut tar_bytes = Vec::new();
+        {
+            let mut builder = tar::Builder::new(&mut tar_bytes);
+            append_json(&mut builder, "manifest.json", &manifest);
+            append_json(&mut builder, "receipts/r1.json", &receipt);
+            builder.finish().unwrap();
+        }
+
+        let mut encoder = GzEncoder::new(Vec::new(), Compression::default());
+        encoder.write_all(&tar_bytes).unwrap();
+        encoder.finish().unwrap()
+    }
+
+    fn append_json<W: Write, T: serde::Serialize>(
+        builder: &mut tar::Builder<W>,
+        path: &str,
+        value: &T,
+    ) {
+        let bytes = serde_json::to_vec(value).unwrap();
+        let mut header = tar::Header::new_gnu();
+        header.set_size(bytes.len() as u64);
+        header.set_mode(0o644);
+        header.set_cksum();
+        builder.append_data(&mut header, path, &bytes[..]).unwrap();
+    }
+
+    #[test]
+    fn test_from_bytes_parses_manifest_and_receipts() {
+        let bundle = EvidenceBundle::from_bytes(&build_bundle()).unwrap();
+        assert_eq!(bundle.sessions().len(), 1);
+        assert_eq!(bundle.receipts_for("s1").len(), 1);
+        assert_eq!(bundle.receipts_for("s1")[0].receipt_id, "r1");
+    }
+}
@@ -1,55 +1,214 @@
 //! HELM SDK — Rust client for the HELM kernel API.
 //! Minimal deps: reqwest + serde.
+//!
+//! The blocking client (`HelmClient`) lives behind the default `blocking`
+//! feature; an async counterpart (`AsyncHelmClient`) lives behind the
+//! `async` feature. See `async_client.rs`.
 
+#[cfg(feature = "blocking")]
 use reqwest::blocking::Client;
+#[cfg(feature = "blocking")]
+use std::sync::Mutex;
+#[cfg(feature = "blocking")]
 use std::time::Duration;
 
+#[cfg(feature = "async")]
+pub mod async_client;
+pub mod auth;
 pub mod client;
+pub mod evidence;
+pub mod retry;
+pub mod signing;
+pub mod streaming;
 pub mod types_gen;
+pub mod verify;
+pub mod version;
+#[cfg(feature = "async")]
+pub use async_client::AsyncHelmClient;
+pub use auth::AuthConfig;
+pub use evidence::{EvidenceBundle, EvidenceBundleError};
+pub use retry::RetryPolicy;
+pub use signing::{HelmSigner, HelmSignerError};
+#[cfg(feature = "async")]
+pub use streaming::AsyncChatCompletionStream;
+#[cfg(feature = "blocking")]
+pub use streaming::ChatCompletionStream;
 pub use types_gen::*;
+pub use verify::verify_chain;
+pub use version::{Capability, Negotiated, SUPPORTED_API_VERSION};
 
-/// Error returned by HELM API calls.
+/// Error returned by HELM API calls, split so callers can tell a
+/// retryable transport blip apart from a deterministic kernel denial.
 #[derive(Debug)]
-pub struct HelmApiError {
-    pub status: u16,
-    pub message: String,
-    pub reason_code: ReasonCode,
+pub enum HelmApiError {
+    /// The request never made it to (or back from) the kernel.
+    Transport(String),
+    /// A response body (from the kernel or the OIDC token endpoint), a
+    /// streamed chunk, or a caller-supplied intent hash couldn't be decoded.
+    Decode(String),
+    /// The kernel or the OIDC token endpoint responded with a non-success
+    /// status, or the kernel sent an error event mid-stream (reported with
+    /// `status: 0`).
+    Api {
+        status: u16,
+        message: String,
+        reason_code: ReasonCode,
+        details: Option<std::collections::HashMap<String, serde_json::Value>>,
+    },
+    /// SDK-side: the kernel's advertised protocol version is incompatible
+    /// with this client, or doesn't support a capability it just used. Not
+    /// a kernel-emitted [`ReasonCode`] — see `version.rs`.
+    VersionMismatch(String),
+}
+
+impl HelmApiError {
+    /// Whether retrying this call could plausibly succeed: transport
+    /// blips and `ERROR_INTERNAL` are retryable, a deterministic `DENY_*`
+    /// or a version mismatch never is.
+    pub fn is_retryable(&self) -> bool {
+        match self {
+            HelmApiError::Transport(_) => true,
+            HelmApiError::Decode(_) => false,
+            HelmApiError::Api { reason_code, .. } => {
+                matches!(reason_code, ReasonCode::ErrorInternal)
+            }
+            HelmApiError::VersionMismatch(_) => false,
+        }
+    }
+
+    /// The kernel's reason code, if this was an API-level error.
+    pub fn reason_code(&self) -> Option<&ReasonCode> {
+        match self {
+            HelmApiError::Api { reason_code, .. } => Some(reason_code),
+            _ => None,
+        }
+    }
 }
 
 impl std::fmt::Display for HelmApiError {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        write!(
-            f,
-            "HELM API {}: {} ({:?})",
-            self.status, self.message, self.reason_code
-        )
+        match self {
+            HelmApiError::Transport(msg) => write!(f, "HELM transport error: {}", msg),
+            HelmApiError::Decode(msg) => write!(f, "HELM response decode error: {}", msg),
+            HelmApiError::Api {
+                status,
+                message,
+                reason_code,
+                ..
+            } => write!(f, "HELM API {}: {} ({:?})", status, message, reason_code),
+            HelmApiError::VersionMismatch(msg) => write!(f, "HELM version mismatch: {}", msg),
+        }
     }
 }
 
 impl std::error::Error for HelmApiError {}
 
 /// Typed client for the HELM kernel API.
+#[cfg(feature = "blocking")]
 pub struct HelmClient {
     base_url: String,
     client: Client,
+    auth: AuthConfig,
+    retry_policy: RetryPolicy,
+    negotiated: Mutex<Option<Negotiated>>,
 }
 
+#[cfg(feature = "blocking")]
 impl HelmClient {
-    /// Create a new client.
+    /// Create a new, unauthenticated client with no retry policy. A `401`
+    /// is still re-sent once, as for every client.
     pub fn new(base_url: &str) -> Self {
+        Self::with_auth(base_url, AuthConfig::none())
+    }
+
+    /// Create a new client that attaches credentials from `auth` to every
+    /// request, refreshing and retrying once on a `401`.
+    pub fn with_auth(base_url: &str, auth: AuthConfig) -> Self {
         Self {
             base_url: base_url.trim_end_matches('/').to_string(),
             client: Client::builder()
                 .timeout(Duration::from_secs(30))
                 .build()
                 .expect("failed to build HTTP client"),
+            auth,
+            retry_policy: RetryPolicy::none(),
+            negotiated: Mutex::new(None),
+        }
+    }
+
+    /// Opt in to retrying retryable failures ([`HelmApiError::is_retryable`])
+    /// under `policy`.
+    pub fn with_retry_policy(mut self, policy: RetryPolicy) -> Self {
+        self.retry_policy = policy;
+        self
+    }
+
+    /// Negotiate protocol capabilities with the kernel, caching the result
+    /// for subsequent calls. Fails with [`HelmApiError::VersionMismatch`] if
+    /// the kernel's major version is incompatible with
+    /// [`SUPPORTED_API_VERSION`].
+    pub fn negotiate(&self) -> Result<Negotiated, HelmApiError> {
+        if let Some(n) = self.negotiated.lock().unwrap().clone() {
+            return Ok(n);
         }
+        let info = self.version()?;
+        let negotiated = Negotiated::from_version_info(info)?;
+        *self.negotiated.lock().unwrap() = Some(negotiated.clone());
+        Ok(negotiated)
     }
 
     fn url(&self, path: &str) -> String {
         format!("{}{}", self.base_url, path)
     }
 
+    /// Build, send, and check a request via `build`: attaches the
+    /// configured auth header and (if `idempotency_key` is set) an
+    /// `Idempotency-Key` header reused across every attempt. Refreshes
+    /// auth and retries once on a `401`, and retries retryable failures
+    /// per the configured [`RetryPolicy`] with backoff between attempts.
+    fn request(
+        &self,
+        idempotency_key: Option<String>,
+        build: impl Fn(&Client) -> reqwest::blocking::RequestBuilder,
+    ) -> Result<reqwest::blocking::Response, HelmApiError> {
+        let mut attempt = 0u32;
+        let mut refreshed_once = false;
+        loop {
+            let outcome = self
+                .send_once(idempotency_key.as_deref(), &build)
+                .and_then(|resp| self.check(resp));
+            match outcome {
+                Ok(resp) => return Ok(resp),
+                Err(HelmApiError::Api { status: 401, .. }) if !refreshed_once => {
+                    refreshed_once = true;
+                    self.auth.invalidate();
+                }
+                Err(e) if e.is_retryable() && attempt < self.retry_policy.max_retries() => {
+                    std::thread::sleep(self.retry_policy.delay_for_attempt(attempt));
+                    attempt += 1;
+                }
+                Err(e) => return Err(e),
+            }
+        }
+    }
+
+    fn send_once(
+        &self,
+        idempotency_key: Option<&str>,
+        build: &impl Fn(&Client) -> reqwest::blocking::RequestBuilder,
+    ) -> Result<reqwest::blocking::Response, HelmApiError> {
+        let mut builder = build(&self.client);
+        if let Some(key) = idempotency_key {
+            builder = builder.header("Idempotency-Key", key);
+        }
+        if let Some(token) = self.auth.token_blocking(&self.client)? {
+            builder = builder.bearer_auth(token.expose_secret());
+        }
+        builder
+            .send()
+            .map_err(|e| HelmApiError::Transport(e.to_string()))
+    }
+
     fn check(
         &self,
         resp: reqwest::blocking::Response,
@@ -59,15 +218,17 @@ impl HelmClient {
         }
         let status = resp.status().as_u16();
         match resp.json::<HelmError>() {
-            Ok(e) => Err(HelmApiError {
+            Ok(e) => Err(HelmApiError::Api {
                 status,
                 message: e.error.message,
                 reason_code: e.error.reason_code,
+                details: e.error.details,
             }),
-            Err(_) => Err(HelmApiError {
+            Err(_) => Err(HelmApiError::Api {
                 status,
                 message: "unknown error".into(),
                 reason_code: ReasonCode::ErrorInternal,
+                details: None,
             }),
         }
     }
@@ -77,185 +238,133 @@ impl HelmClient {
         &self,
         req: &ChatCompletionRequest,
     ) -> Result<ChatCompletionResponse, HelmApiError> {
-        let resp = self
-            .client
-            .post(self.url("/v1/chat/completions"))
-            .json(req)
-            .send()
-            .map_err(|e| HelmApiError {
-                status: 0,
-                message: e.to_string(),
-                reason_code: ReasonCode::ErrorInternal,
-            })?;
-        let resp = self.check(resp)?;
-        resp.json().map_err(|e| HelmApiError {
-            status: 0,
-            message: e.to_string(),
-            reason_code: ReasonCode::ErrorInternal,
-        })
+        let key = retry::generate_idempotency_key();
+        let resp = self.request(Some(key), |c| {
+            c.post(self.url("/v1/chat/completions")).json(req)
+        })?;
+        resp.json().map_err(|e| HelmApiError::Decode(e.to_string()))
+    }
+
+    /// POST /v1/chat/completions with `stream: true` — returns an iterator
+    /// of incremental [`ChatCompletionChunk`] deltas instead of a single
+    /// full response.
+    pub fn chat_completions_stream(
+        &self,
+        req: &ChatCompletionRequest,
+    ) -> Result<ChatCompletionStream, HelmApiError> {
+        self.negotiate()?.require(Capability::Streaming)?;
+        let mut streamed = req.clone();
+        streamed.stream = Some(true);
+        let resp = self.request(None, |c| {
+            c.post(self.url("/v1/chat/completions")).json(&streamed)
+        })?;
+        Ok(ChatCompletionStream::new(resp))
     }
 
     /// POST /api/v1/kernel/approve
+    ///
+    /// If a retried submission comes back as `DENY_IDEMPOTENCY_DUPLICATE`
+    /// and the kernel included the original `receipt_id` in the denial
+    /// details, fetches and returns that receipt instead of surfacing the
+    /// duplicate as a new failure.
     pub fn approve_intent(&self, req: &ApprovalRequest) -> Result<Receipt, HelmApiError> {
-        let resp = self
-            .client
-            .post(self.url("/api/v1/kernel/approve"))
-            .json(req)
-            .send()
-            .map_err(|e| HelmApiError {
-                status: 0,
-                message: e.to_string(),
-                reason_code: ReasonCode::ErrorInternal,
-            })?;
-        let resp = self.check(resp)?;
-        resp.json().map_err(|e| HelmApiError {
-            status: 0,
-            message: e.to_string(),
-            reason_code: ReasonCode::ErrorInternal,
-        })
+        let key = retry::generate_idempotency_key();
+        match self.request(Some(key), |c| {
+            c.post(self.url("/api/v1/kernel/approve")).json(req)
+        }) {
+            Ok(resp) => resp.json().map_err(|e| HelmApiError::Decode(e.to_string())),
+            Err(HelmApiError::Api {
+                reason_code: ReasonCode::DenyIdempotencyDuplicate,
+                details: Some(details),
+                ..
+            }) if details.get("receipt_id").and_then(|v| v.as_str()).is_some() => {
+                self.get_receipt(details["receipt_id"].as_str().unwrap())
+            }
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Sign `intent_hash` with `signer` and submit it to
+    /// /api/v1/kernel/approve in one call, so callers never hand-assemble
+    /// signature material.
+    pub fn approve_intent_signed(
+        &self,
+        intent_hash: &str,
+        signer: &HelmSigner,
+    ) -> Result<Receipt, HelmApiError> {
+        let req = signer
+            .sign_intent(intent_hash, None)
+            .map_err(|e| HelmApiError::Decode(e.to_string()))?;
+        self.approve_intent(&req)
     }
 
     /// GET /api/v1/proofgraph/sessions
     pub fn list_sessions(&self) -> Result<Vec<Session>, HelmApiError> {
-        let resp = self
-            .client
-            .get(self.url("/api/v1/proofgraph/sessions"))
-            .send()
-            .map_err(|e| HelmApiError {
-                status: 0,
-                message: e.to_string(),
-                reason_code: ReasonCode::ErrorInternal,
-            })?;
-        let resp = self.check(resp)?;
-        resp.json().map_err(|e| HelmApiError {
-            status: 0,
-            message: e.to_string(),
-            reason_code: ReasonCode::ErrorInternal,
-        })
+        let resp = self.request(None, |c| c.get(self.url("/api/v1/proofgraph/sessions")))?;
+        resp.json().map_err(|e| HelmApiError::Decode(e.to_string()))
     }
 
     /// GET /api/v1/proofgraph/sessions/{id}/receipts
     pub fn get_receipts(&self, session_id: &str) -> Result<Vec<Receipt>, HelmApiError> {
-        let resp = self
-            .client
-            .get(self.url(&format!(
+        let resp = self.request(None, |c| {
+            c.get(self.url(&format!(
                 "/api/v1/proofgraph/sessions/{}/receipts",
                 session_id
             )))
-            .send()
-            .map_err(|e| HelmApiError {
-                status: 0,
-                message: e.to_string(),
-                reason_code: ReasonCode::ErrorInternal,
-            })?;
-        let resp = self.check(resp)?;
-        resp.json().map_err(|e| HelmApiError {
-            status: 0,
-            message: e.to_string(),
-            reason_code: ReasonCode::ErrorInternal,
-        })
+        })?;
+        resp.json().map_err(|e| HelmApiError::Decode(e.to_string()))
     }
 
     /// POST /api/v1/evidence/export — returns raw bytes
     pub fn export_evidence(&self, session_id: Option<&str>) -> Result<Vec<u8>, HelmApiError> {
-        let body = serde_json::json!({
-            "session_id": session_id,
-            "format": "tar.gz"
-        });
-        let resp = self
-            .client
-            .post(self.url("/api/v1/evidence/export"))
-            .json(&body)
-            .send()
-            .map_err(|e| HelmApiError {
-                status: 0,
-                message: e.to_string(),
-                reason_code: ReasonCode::ErrorInternal,
-            })?;
-        let resp = self.check(resp)?;
+        let resp = self.request(None, |c| {
+            let body = serde_json::json!({
+                "session_id": session_id,
+                "format": "tar.gz"
+            });
+            c.post(self.url("/api/v1/evidence/export")).json(&body)
+        })?;
         resp.bytes()
             .map(|b| b.to_vec())
-            .map_err(|e| HelmApiError {
-                status: 0,
-                message: e.to_string(),
-                reason_code: ReasonCode::ErrorInternal,
-            })
+            .map_err(|e| HelmApiError::Decode(e.to_string()))
     }
 
     /// POST /api/v1/evidence/verify
     pub fn verify_evidence(&self, bundle: &[u8]) -> Result<VerificationResult, HelmApiError> {
-        let form = reqwest::blocking::multipart::Form::new().part(
-            "bundle",
-            reqwest::blocking::multipart::Part::bytes(bundle.to_vec())
-                .file_name("pack.tar.gz")
-                .mime_str("application/octet-stream")
-                .unwrap(),
-        );
-        let resp = self
-            .client
-            .post(self.url("/api/v1/evidence/verify"))
-            .multipart(form)
-            .send()
-            .map_err(|e| HelmApiError {
-                status: 0,
-                message: e.to_string(),
-                reason_code: ReasonCode::ErrorInternal,
-            })?;
-        let resp = self.check(resp)?;
-        resp.json().map_err(|e| HelmApiError {
-            status: 0,
-            message: e.to_string(),
-            reason_code: ReasonCode::ErrorInternal,
-        })
+        let resp = self.request(None, |c| {
+            let form = reqwest::blocking::multipart::Form::new().part(
+                "bundle",
+                reqwest::blocking::multipart::Part::bytes(bundle.to_vec())
+                    .file_name("pack.tar.gz")
+                    .mime_str("application/octet-stream")
+                    .unwrap(),
+            );
+            c.post(self.url("/api/v1/evidence/verify")).multipart(form)
+        })?;
+        resp.json().map_err(|e| HelmApiError::Decode(e.to_string()))
     }
 
     /// POST /api/v1/replay/verify
     pub fn replay_verify(&self, bundle: &[u8]) -> Result<VerificationResult, HelmApiError> {
-        let form = reqwest::blocking::multipart::Form::new().part(
-            "bundle",
-            reqwest::blocking::multipart::Part::bytes(bundle.to_vec())
-                .file_name("pack.tar.gz")
-                .mime_str("application/octet-stream")
-                .unwrap(),
-        );
-        let resp = self
-            .client
-            .post(self.url("/api/v1/replay/verify"))
-            .multipart(form)
-            .send()
-            .map_err(|e| HelmApiError {
-                status: 0,
-                message: e.to_string(),
-                reason_code: ReasonCode::ErrorInternal,
-            })?;
-        let resp = self.check(resp)?;
-        resp.json().map_err(|e| HelmApiError {
-            status: 0,
-            message: e.to_string(),
-            reason_code: ReasonCode::ErrorInternal,
-        })
+        let resp = self.request(None, |c| {
+            let form = reqwest::blocking::multipart::Form::new().part(
+                "bundle",
+                reqwest::blocking::multipart::Part::bytes(bundle.to_vec())
+                    .file_name("pack.tar.gz")
+                    .mime_str("application/octet-stream")
+                    .unwrap(),
+            );
+            c.post(self.url("/api/v1/replay/verify")).multipart(form)
+        })?;
+        resp.json().map_err(|e| HelmApiError::Decode(e.to_string()))
     }
 
     /// GET /api/v1/proofgraph/receipts/{hash}
     pub fn get_receipt(&self, receipt_hash: &str) -> Result<Receipt, HelmApiError> {
-        let resp = self
-            .client
-            .get(self.url(&format!(
-                "/api/v1/proofgraph/receipts/{}",
-                receipt_hash
-            )))
-            .send()
-            .map_err(|e| HelmApiError {
-                status: 0,
-                message: e.to_string(),
-                reason_code: ReasonCode::ErrorInternal,
-            })?;
-        let resp = self.check(resp)?;
-        resp.json().map_err(|e| HelmApiError {
-            status: 0,
-            message: e.to_string(),
-            reason_code: ReasonCode::ErrorInternal,
-        })
+        let resp = self.request(None, |c| {
+            c.get(self.url(&format!("/api/v1/proofgraph/receipts/{}", receipt_hash)))
+        })?;
+        resp.json().map_err(|e| HelmApiError::Decode(e.to_string()))
     }
 
     /// POST /api/v1/conformance/run
@@ -263,22 +372,11 @@ impl HelmClient {
         &self,
         req: &ConformanceRequest,
     ) -> Result<ConformanceResult, HelmApiError> {
-        let resp = self
-            .client
-            .post(self.url("/api/v1/conformance/run"))
-            .json(req)
-            .send()
-            .map_err(|e| HelmApiError {
-                status: 0,
-                message: e.to_string(),
-                reason_code: ReasonCode::ErrorInternal,
-            })?;
-        let resp = self.check(resp)?;
-        resp.json().map_err(|e| HelmApiError {
-            status: 0,
-            message: e.to_string(),
-            reason_code: ReasonCode::ErrorInternal,
-        })
+        let key = retry::generate_idempotency_key();
+        let resp = self.request(Some(key), |c| {
+            c.post(self.url("/api/v1/conformance/run")).json(req)
+        })?;
+        resp.json().map_err(|e| HelmApiError::Decode(e.to_string()))
     }
 
     /// GET /api/v1/conformance/reports/{id}
@@ -286,66 +384,26 @@ impl HelmClient {
         &self,
         report_id: &str,
     ) -> Result<ConformanceResult, HelmApiError> {
-        let resp = self
-            .client
-            .get(self.url(&format!(
-                "/api/v1/conformance/reports/{}",
-                report_id
-            )))
-            .send()
-            .map_err(|e| HelmApiError {
-                status: 0,
-                message: e.to_string(),
-                reason_code: ReasonCode::ErrorInternal,
-            })?;
-        let resp = self.check(resp)?;
-        resp.json().map_err(|e| HelmApiError {
-            status: 0,
-            message: e.to_string(),
-            reason_code: ReasonCode::ErrorInternal,
-        })
+        let resp = self.request(None, |c| {
+            c.get(self.url(&format!("/api/v1/conformance/reports/{}", report_id)))
+        })?;
+        resp.json().map_err(|e| HelmApiError::Decode(e.to_string()))
     }
 
     /// GET /healthz
     pub fn health(&self) -> Result<serde_json::Value, HelmApiError> {
-        let resp = self
-            .client
-            .get(self.url("/healthz"))
-            .send()
-            .map_err(|e| HelmApiError {
-                status: 0,
-                message: e.to_string(),
-                reason_code: ReasonCode::ErrorInternal,
-            })?;
-        let resp = self.check(resp)?;
-        resp.json().map_err(|e| HelmApiError {
-            status: 0,
-            message: e.to_string(),
-            reason_code: ReasonCode::ErrorInternal,
-        })
+        let resp = self.request(None, |c| c.get(self.url("/healthz")))?;
+        resp.json().map_err(|e| HelmApiError::Decode(e.to_string()))
     }
 
     /// GET /version
     pub fn version(&self) -> Result<VersionInfo, HelmApiError> {
-        let resp = self
-            .client
-            .get(self.url("/version"))
-            .send()
-            .map_err(|e| HelmApiError {
-                status: 0,
-                message: e.to_string(),
-                reason_code: ReasonCode::ErrorInternal,
-            })?;
-        let resp = self.check(resp)?;
-        resp.json().map_err(|e| HelmApiError {
-            status: 0,
-            message: e.to_string(),
-            reason_code: ReasonCode::ErrorInternal,
-        })
+        let resp = self.request(None, |c| c.get(self.url("/version")))?;
+        resp.json().map_err(|e| HelmApiError::Decode(e.to_string()))
     }
 }
 
-#[cfg(test)]
+#[cfg(all(test, feature = "blocking"))]
 mod tests {
     use super::*;
 
@@ -360,4 +418,25 @@ mod tests {
         let json = serde_json::to_string(&code).unwrap();
         assert_eq!(json, "\"DENY_TOOL_NOT_FOUND\"");
     }
+
+    #[test]
+    fn test_is_retryable() {
+        assert!(HelmApiError::Transport("boom".into()).is_retryable());
+        assert!(!HelmApiError::Decode("boom".into()).is_retryable());
+        assert!(HelmApiError::Api {
+            status: 500,
+            message: "boom".into(),
+            reason_code: ReasonCode::ErrorInternal,
+            details: None,
+        }
+        .is_retryable());
+        assert!(!HelmApiError::Api {
+            status: 403,
+            message: "denied".into(),
+            reason_code: ReasonCode::DenyPolicyViolation,
+            details: None,
+        }
+        .is_retryable());
+        assert!(!HelmApiError::VersionMismatch("boom".into()).is_retryable());
+    }
 }
@@ -0,0 +1,93 @@
+// HELM SDK — opt-in retry policy with exponential backoff + jitter, and
+// Idempotency-Key generation for mutating calls.
+//
+// Retries only kick in for `HelmApiError::is_retryable()` errors (transport
+// blips and `ERROR_INTERNAL`) — a deterministic `DENY_*` is never retried.
+
+use std::time::Duration;
+
+use rand::Rng;
+
+/// Opt-in retry policy for transient failures.
+#[derive(Debug, Clone)]
+pub struct RetryPolicy {
+    pub(crate) max_retries: u32,
+    pub(crate) base_delay: Duration,
+    pub(crate) max_delay: Duration,
+}
+
+impl RetryPolicy {
+    /// No retries — the default.
+    pub fn none() -> Self {
+        Self {
+            max_retries: 0,
+            base_delay: Duration::ZERO,
+            max_delay: Duration::ZERO,
+        }
+    }
+
+    /// Retry up to `max_retries` times with full-jitter exponential
+    /// backoff: each attempt waits a random duration between zero and
+    /// `min(max_delay, base_delay * 2^attempt)`.
+    pub fn exponential_backoff(
+        max_retries: u32,
+        base_delay: Duration,
+        max_delay: Duration,
+    ) -> Self {
+        Self {
+            max_retries,
+            base_delay,
+            max_delay,
+        }
+    }
+
+    pub(crate) fn max_retries(&self) -> u32 {
+        self.max_retries
+    }
+
+    pub(crate) fn delay_for_attempt(&self, attempt: u32) -> Duration {
+        let exponential = self
+            .base_delay
+            .saturating_mul(1u32.checked_shl(attempt).unwrap_or(u32::MAX).max(1));
+        let capped = exponential.min(self.max_delay);
+        let jitter: f64 = rand::thread_rng().gen_range(0.0..=1.0);
+        capped.mul_f64(jitter)
+    }
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        Self::none()
+    }
+}
+
+/// Generate a fresh `Idempotency-Key` for a mutating call. The same key
+/// must be reused across retries of that one logical call so the kernel
+/// can recognize a retried request as a duplicate of the original.
+pub(crate) fn generate_idempotency_key() -> String {
+    uuid::Uuid::new_v4().to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_delay_for_attempt_is_capped() {
+        let policy =
+            RetryPolicy::exponential_backoff(5, Duration::from_millis(100), Duration::from_secs(1));
+        for attempt in 0..10 {
+            assert!(policy.delay_for_attempt(attempt) <= Duration::from_secs(1));
+        }
+    }
+
+    #[test]
+    fn test_none_policy_has_no_retries() {
+        assert_eq!(RetryPolicy::none().max_retries(), 0);
+    }
+
+    #[test]
+    fn test_idempotency_keys_are_unique() {
+        assert_ne!(generate_idempotency_key(), generate_idempotency_key());
+    }
+}
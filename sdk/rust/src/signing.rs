@@ -0,0 +1,130 @@
+// HELM SDK — client-side Ed25519 signing for `approve_intent`.
+//
+// `ApprovalRequest` requires `signature_b64`/`public_key_b64` but leaves
+// producing them to the caller. `HelmSigner` wraps an ed25519-dalek
+// keypair and turns an intent hash into a ready-to-submit request.
+
+use ed25519_dalek::pkcs8::DecodePrivateKey;
+use ed25519_dalek::{Signer, SigningKey};
+use rand_core::OsRng;
+
+use crate::ApprovalRequest;
+
+/// Error producing signature material for an [`ApprovalRequest`].
+#[derive(Debug)]
+pub enum HelmSignerError {
+    /// `intent_hash` was not valid hex.
+    InvalidIntentHash(String),
+    /// The provided key material was malformed.
+    InvalidKey(String),
+}
+
+impl std::fmt::Display for HelmSignerError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            HelmSignerError::InvalidIntentHash(msg) => write!(f, "invalid intent hash: {}", msg),
+            HelmSignerError::InvalidKey(msg) => write!(f, "invalid key material: {}", msg),
+        }
+    }
+}
+
+impl std::error::Error for HelmSignerError {}
+
+/// Signs intent hashes for `approve_intent` with an Ed25519 keypair.
+pub struct HelmSigner {
+    signing_key: SigningKey,
+}
+
+impl HelmSigner {
+    /// Build a signer from a 32-byte Ed25519 seed.
+    pub fn from_seed(seed: &[u8; 32]) -> Self {
+        Self {
+            signing_key: SigningKey::from_bytes(seed),
+        }
+    }
+
+    /// Build a signer from a PKCS#8 DER-encoded private key.
+    pub fn from_pkcs8(der: &[u8]) -> Result<Self, HelmSignerError> {
+        let signing_key = SigningKey::from_pkcs8_der(der)
+            .map_err(|e| HelmSignerError::InvalidKey(e.to_string()))?;
+        Ok(Self { signing_key })
+    }
+
+    /// Generate a fresh random keypair.
+    pub fn generate() -> Self {
+        Self {
+            signing_key: SigningKey::generate(&mut OsRng),
+        }
+    }
+
+    /// Base64 (standard alphabet, padded) encoding of the signer's public key.
+    pub fn public_key_b64(&self) -> String {
+        use base64::Engine;
+        base64::engine::general_purpose::STANDARD
+            .encode(self.signing_key.verifying_key().as_bytes())
+    }
+
+    /// Sign `intent_hash` (hex-encoded) and assemble a ready-to-submit
+    /// [`ApprovalRequest`], optionally folding in a `challenge_response`.
+    pub fn sign_intent(
+        &self,
+        intent_hash: &str,
+        challenge_response: Option<String>,
+    ) -> Result<ApprovalRequest, HelmSignerError> {
+        use base64::Engine;
+
+        let raw = hex::decode(intent_hash)
+            .map_err(|e| HelmSignerError::InvalidIntentHash(e.to_string()))?;
+        let signature = self.signing_key.sign(&raw);
+
+        Ok(ApprovalRequest {
+            intent_hash: intent_hash.to_string(),
+            signature_b64: base64::engine::general_purpose::STANDARD.encode(signature.to_bytes()),
+            public_key_b64: self.public_key_b64(),
+            challenge_response,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_sign_intent_roundtrip() {
+        let signer = HelmSigner::from_seed(&[7u8; 32]);
+        let intent_hash = hex::encode([1u8; 32]);
+        let req = signer.sign_intent(&intent_hash, None).unwrap();
+        assert_eq!(req.intent_hash, intent_hash);
+        assert!(!req.signature_b64.is_empty());
+        assert_eq!(req.public_key_b64, signer.public_key_b64());
+        assert!(req.challenge_response.is_none());
+    }
+
+    #[test]
+    fn test_sign_intent_rejects_non_hex() {
+        let signer = HelmSigner::from_seed(&[1u8; 32]);
+        assert!(signer.sign_intent("not-hex!", None).is_err());
+    }
+
+    #[test]
+    fn test_from_pkcs8_roundtrip() {
+        use ed25519_dalek::pkcs8::EncodePrivateKey;
+
+        let seeded = HelmSigner::from_seed(&[9u8; 32]);
+        let der = seeded
+            .signing_key
+            .to_pkcs8_der()
+            .expect("encode PKCS#8 DER")
+            .as_bytes()
+            .to_vec();
+
+        let signer = HelmSigner::from_pkcs8(&der).expect("decode PKCS#8 DER");
+        assert_eq!(signer.public_key_b64(), seeded.public_key_b64());
+    }
+
+    #[test]
+    fn test_from_pkcs8_rejects_garbage() {
+        assert!(HelmSigner::from_pkcs8(&[0u8; 32]).is_err());
+    }
+}
@@ -0,0 +1,244 @@
+// HELM SDK — Server-Sent Events streaming for `chat/completions`.
+//
+// The kernel streams `text/event-stream` bodies framed as `data: <json>\n\n`
+// blocks terminated by a `data: [DONE]` sentinel. A block may instead carry
+// a HELM denial (`{"error": {...}}`), which must surface as a
+// `HelmApiError` rather than being dropped silently — callers need to see
+// `DENY_*` verdicts even on streamed traffic.
+
+use crate::{ChatCompletionChunk, HelmApiError, HelmError};
+
+const DONE_SENTINEL: &str = "[DONE]";
+
+/// Outcome of parsing one `data: ...` SSE block.
+enum SseEvent {
+    Chunk(ChatCompletionChunk),
+    Error(HelmApiError),
+    Done,
+}
+
+fn parse_block(block: &str) -> Option<SseEvent> {
+    let data = block
+        .lines()
+        .find_map(|line| {
+            line.strip_prefix("data: ")
+                .or_else(|| line.strip_prefix("data:"))
+        })?
+        .trim();
+    if data.is_empty() {
+        return None;
+    }
+    if data == DONE_SENTINEL {
+        return Some(SseEvent::Done);
+    }
+    if let Ok(err) = serde_json::from_str::<HelmError>(data) {
+        return Some(SseEvent::Error(HelmApiError::Api {
+            status: 0,
+            message: err.error.message,
+            reason_code: err.error.reason_code,
+            details: err.error.details,
+        }));
+    }
+    match serde_json::from_str::<ChatCompletionChunk>(data) {
+        Ok(chunk) => Some(SseEvent::Chunk(chunk)),
+        Err(e) => Some(SseEvent::Error(HelmApiError::Decode(e.to_string()))),
+    }
+}
+
+/// Incrementally splits a raw SSE byte stream into `data: ...` blocks
+/// separated by blank lines (`\n\n`).
+///
+/// Buffers raw bytes rather than decoding each `push` independently:
+/// `read()`/`chunk()` boundaries never align to UTF-8 char boundaries, so
+/// decoding eagerly would permanently mangle a multi-byte character split
+/// across two pushes into replacement characters on both sides of the
+/// split. UTF-8 decoding only happens once a full `\n\n`-delimited block
+/// has been assembled.
+#[derive(Default)]
+pub(crate) struct SseSplitter {
+    buf: Vec<u8>,
+}
+
+impl SseSplitter {
+    pub(crate) fn push(&mut self, bytes: &[u8]) {
+        self.buf.extend_from_slice(bytes);
+    }
+
+    /// Pop the next complete block, if the buffer has one.
+    pub(crate) fn next_event(&mut self) -> Option<Result<ChatCompletionChunk, HelmApiError>> {
+        loop {
+            let idx = self.buf.windows(2).position(|w| w == b"\n\n")?;
+            let block_bytes: Vec<u8> = self.buf.drain(..idx + 2).collect();
+            let block = String::from_utf8_lossy(&block_bytes[..idx]);
+            match parse_block(&block) {
+                Some(SseEvent::Chunk(c)) => return Some(Ok(c)),
+                Some(SseEvent::Error(e)) => return Some(Err(e)),
+                Some(SseEvent::Done) => return None,
+                None => continue,
+            }
+        }
+    }
+}
+
+#[cfg(feature = "blocking")]
+mod blocking_stream {
+    use super::SseSplitter;
+    use crate::{ChatCompletionChunk, HelmApiError};
+    use std::io::Read;
+
+    /// Blocking iterator over incremental [`ChatCompletionChunk`] deltas.
+    pub struct ChatCompletionStream {
+        resp: reqwest::blocking::Response,
+        splitter: SseSplitter,
+        done: bool,
+    }
+
+    impl ChatCompletionStream {
+        pub(crate) fn new(resp: reqwest::blocking::Response) -> Self {
+            Self {
+                resp,
+                splitter: SseSplitter::default(),
+                done: false,
+            }
+        }
+    }
+
+    impl Iterator for ChatCompletionStream {
+        type Item = Result<ChatCompletionChunk, HelmApiError>;
+
+        fn next(&mut self) -> Option<Self::Item> {
+            if self.done {
+                return None;
+            }
+            loop {
+                if let Some(event) = self.splitter.next_event() {
+                    if event.is_err() {
+                        self.done = true;
+                    }
+                    return Some(event);
+                }
+                let mut buf = [0u8; 8192];
+                match self.resp.read(&mut buf) {
+                    Ok(0) => {
+                        self.done = true;
+                        return None;
+                    }
+                    Ok(n) => self.splitter.push(&buf[..n]),
+                    Err(e) => {
+                        self.done = true;
+                        return Some(Err(HelmApiError::Transport(e.to_string())));
+                    }
+                }
+            }
+        }
+    }
+}
+
+#[cfg(feature = "blocking")]
+pub use blocking_stream::ChatCompletionStream;
+
+#[cfg(feature = "async")]
+mod async_stream {
+    use super::SseSplitter;
+    use crate::{ChatCompletionChunk, HelmApiError};
+
+    /// Async stream of incremental [`ChatCompletionChunk`] deltas.
+    pub struct AsyncChatCompletionStream {
+        resp: reqwest::Response,
+        splitter: SseSplitter,
+        done: bool,
+    }
+
+    impl AsyncChatCompletionStream {
+        pub(crate) fn new(resp: reqwest::Response) -> Self {
+            Self {
+                resp,
+                splitter: SseSplitter::default(),
+                done: false,
+            }
+        }
+
+        /// Fetch the next chunk, or `None` once the stream has ended.
+        pub async fn next(&mut self) -> Option<Result<ChatCompletionChunk, HelmApiError>> {
+            if self.done {
+                return None;
+            }
+            loop {
+                if let Some(event) = self.splitter.next_event() {
+                    if event.is_err() {
+                        self.done = true;
+                    }
+                    return Some(event);
+                }
+                match self.resp.chunk().await {
+                    Ok(Some(bytes)) => self.splitter.push(&bytes),
+                    Ok(None) => {
+                        self.done = true;
+                        return None;
+                    }
+                    Err(e) => {
+                        self.done = true;
+                        return Some(Err(HelmApiError::Transport(e.to_string())));
+                    }
+                }
+            }
+        }
+    }
+}
+
+#[cfg(feature = "async")]
+pub use async_stream::AsyncChatCompletionStream;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_splitter_parses_chunk_and_done() {
+        let mut splitter = SseSplitter::default();
+        splitter.push(
+            b"data: {\"id\":\"1\",\"object\":\"chat.completion.chunk\",\"created\":0,\"model\":\"gpt-4\",\"choices\":[]}\n\n\
+              data: [DONE]\n\n",
+        );
+        let first = splitter.next_event().expect("expected a chunk");
+        assert!(first.is_ok());
+        assert!(splitter.next_event().is_none());
+    }
+
+    #[test]
+    fn test_splitter_survives_multibyte_char_split_across_pushes() {
+        let content = "héllo 😀 world";
+        let mut payload = serde_json::to_vec(&serde_json::json!({
+            "id": "1",
+            "object": "chat.completion.chunk",
+            "created": 0,
+            "model": "gpt-4",
+            "choices": [{"index": 0, "delta": {"content": content}}],
+        }))
+        .unwrap();
+        let mut block = b"data: ".to_vec();
+        block.append(&mut payload);
+        block.extend_from_slice(b"\n\n");
+
+        // Split the push right in the middle of the multi-byte emoji.
+        let emoji_start = block.windows(4).position(|w| w == "😀".as_bytes()).unwrap();
+        let split_at = emoji_start + 2;
+
+        let mut splitter = SseSplitter::default();
+        splitter.push(&block[..split_at]);
+        splitter.push(&block[split_at..]);
+
+        let event = splitter.next_event().expect("expected a chunk").unwrap();
+        assert_eq!(event.choices[0].delta.content.as_deref(), Some(content));
+    }
+
+    #[test]
+    fn test_splitter_surfaces_deny_as_error() {
+        let mut splitter = SseSplitter::default();
+        splitter.push(
+            b"data: {\"error\":{\"message\":\"denied\",\"type\":\"deny\",\"code\":\"deny\",\"reason_code\":\"DENY_POLICY_VIOLATION\",\"details\":null}}\n\n",
+        );
+        let event = splitter.next_event().expect("expected an error event");
+        assert!(event.is_err());
+    }
+}
@@ -0,0 +1,260 @@
+// HELM SDK — offline verification of receipt hash-chains and signatures.
+//
+// `Receipt` already carries everything needed to check integrity locally
+// (`prev_hash`, `output_hash`, `blob_hash`, `lamport_clock`, `signature`,
+// `principal`), so auditors shouldn't need a server round-trip just to
+// check an exported bundle. `verify_chain` mirrors the shape of the
+// server-side `VerificationResult` so the two can be compared directly.
+
+use std::collections::{BTreeMap, HashMap};
+
+use ed25519_dalek::{Signature, Verifier, VerifyingKey};
+use sha2::{Digest, Sha256};
+
+use crate::{Receipt, VerificationResult};
+
+const GENESIS_PREV_HASH: &str = "0000000000000000000000000000000000000000000000000000000000000000";
+
+/// Canonical (field-sorted) content bytes of a receipt, excluding the
+/// signature itself — this is what `prev_hash` chains to and what
+/// `signature` signs.
+fn canonical_content_bytes(r: &Receipt) -> Vec<u8> {
+    let mut fields: BTreeMap<&str, serde_json::Value> = BTreeMap::new();
+    fields.insert(
+        "receipt_id",
+        serde_json::Value::String(r.receipt_id.clone()),
+    );
+    fields.insert(
+        "decision_id",
+        serde_json::Value::String(r.decision_id.clone()),
+    );
+    fields.insert("effect_id", serde_json::Value::String(r.effect_id.clone()));
+    fields.insert("status", serde_json::Value::String(r.status.clone()));
+    fields.insert(
+        "reason_code",
+        serde_json::Value::String(r.reason_code.clone()),
+    );
+    fields.insert(
+        "output_hash",
+        serde_json::Value::String(r.output_hash.clone()),
+    );
+    fields.insert("blob_hash", serde_json::Value::String(r.blob_hash.clone()));
+    fields.insert("prev_hash", serde_json::Value::String(r.prev_hash.clone()));
+    fields.insert(
+        "lamport_clock",
+        serde_json::Value::Number(r.lamport_clock.into()),
+    );
+    fields.insert("timestamp", serde_json::Value::String(r.timestamp.clone()));
+    fields.insert("principal", serde_json::Value::String(r.principal.clone()));
+    serde_json::to_vec(&fields).expect("canonical receipt fields always serialize")
+}
+
+/// Recompute the content hash a receipt contributes to the chain, as a
+/// lowercase hex string.
+fn content_hash(r: &Receipt) -> String {
+    let digest = Sha256::digest(canonical_content_bytes(r));
+    hex::encode(digest)
+}
+
+/// Verify the integrity of an exported receipt chain without any network
+/// access: hash-chain linkage, gap-free Lamport clocks (each one exactly
+/// one more than the last), and
+/// Ed25519 signatures over each receipt's canonical content.
+///
+/// `signing_keys` maps `principal` to the `VerifyingKey` that should have
+/// produced that receipt's `signature`.
+pub fn verify_chain(
+    receipts: &[Receipt],
+    signing_keys: &HashMap<String, VerifyingKey>,
+) -> VerificationResult {
+    let mut sorted: Vec<&Receipt> = receipts.iter().collect();
+    sorted.sort_by_key(|r| r.lamport_clock);
+
+    let mut errors = Vec::new();
+    let mut checks = HashMap::new();
+
+    checks.insert(
+        "chain_linkage".to_string(),
+        check_chain_linkage(&sorted, &mut errors),
+    );
+    checks.insert(
+        "lamport_monotonic".to_string(),
+        check_lamport_monotonic(&sorted, &mut errors),
+    );
+    checks.insert(
+        "signatures".to_string(),
+        check_signatures(&sorted, signing_keys, &mut errors),
+    );
+
+    let verdict = if errors.is_empty() { "PASS" } else { "FAIL" };
+    VerificationResult {
+        verdict: verdict.to_string(),
+        checks,
+        errors,
+    }
+}
+
+fn check_chain_linkage(sorted: &[&Receipt], errors: &mut Vec<String>) -> String {
+    let mut ok = true;
+    if let Some(genesis) = sorted.first() {
+        if genesis.prev_hash != GENESIS_PREV_HASH {
+            ok = false;
+            errors.push(format!(
+                "genesis receipt {} has non-zero prev_hash",
+                genesis.receipt_id
+            ));
+        }
+    }
+    for window in sorted.windows(2) {
+        let (prev, next) = (window[0], window[1]);
+        let expected = content_hash(prev);
+        if next.prev_hash != expected {
+            ok = false;
+            errors.push(format!(
+                "receipt {} prev_hash does not chain to receipt {}",
+                next.receipt_id, prev.receipt_id
+            ));
+        }
+    }
+    pass_fail(ok)
+}
+
+fn check_lamport_monotonic(sorted: &[&Receipt], errors: &mut Vec<String>) -> String {
+    let mut ok = true;
+    for window in sorted.windows(2) {
+        let (prev, next) = (window[0], window[1]);
+        if next.lamport_clock != prev.lamport_clock + 1 {
+            ok = false;
+            errors.push(format!(
+                "lamport clock gap between receipts {} ({}) and {} ({})",
+                prev.receipt_id, prev.lamport_clock, next.receipt_id, next.lamport_clock
+            ));
+        }
+    }
+    pass_fail(ok)
+}
+
+fn check_signatures(
+    sorted: &[&Receipt],
+    signing_keys: &HashMap<String, VerifyingKey>,
+    errors: &mut Vec<String>,
+) -> String {
+    let mut ok = true;
+    for receipt in sorted {
+        let Some(key) = signing_keys.get(&receipt.principal) else {
+            ok = false;
+            errors.push(format!(
+                "no verifying key supplied for principal {} (receipt {})",
+                receipt.principal, receipt.receipt_id
+            ));
+            continue;
+        };
+        let sig_bytes = match base64_decode(&receipt.signature) {
+            Ok(b) => b,
+            Err(e) => {
+                ok = false;
+                errors.push(format!(
+                    "receipt {} has malformed signature: {}",
+                    receipt.receipt_id, e
+                ));
+                continue;
+            }
+        };
+        let signature = match Signature::from_slice(&sig_bytes) {
+            Ok(s) => s,
+            Err(e) => {
+                ok = false;
+                errors.push(format!(
+                    "receipt {} has malformed signature: {}",
+                    receipt.receipt_id, e
+                ));
+                continue;
+            }
+        };
+        if key
+            .verify(&canonical_content_bytes(receipt), &signature)
+            .is_err()
+        {
+            ok = false;
+            errors.push(format!(
+                "receipt {} signature does not verify for principal {}",
+                receipt.receipt_id, receipt.principal
+            ));
+        }
+    }
+    pass_fail(ok)
+}
+
+fn base64_decode(s: &str) -> Result<Vec<u8>, base64::DecodeError> {
+    use base64::Engine;
+    base64::engine::general_purpose::STANDARD.decode(s)
+}
+
+fn pass_fail(ok: bool) -> String {
+    if ok {
+        "pass".to_string()
+    } else {
+        "fail".to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ed25519_dalek::{Signer, SigningKey};
+
+    fn signed_receipt(
+        key: &SigningKey,
+        receipt_id: &str,
+        prev_hash: &str,
+        lamport_clock: i64,
+    ) -> Receipt {
+        let mut receipt = Receipt {
+            receipt_id: receipt_id.to_string(),
+            decision_id: "d1".to_string(),
+            effect_id: "e1".to_string(),
+            status: "ALLOW".to_string(),
+            reason_code: "ALLOW".to_string(),
+            output_hash: "oh".to_string(),
+            blob_hash: "bh".to_string(),
+            prev_hash: prev_hash.to_string(),
+            lamport_clock,
+            signature: String::new(),
+            timestamp: "2026-01-01T00:00:00Z".to_string(),
+            principal: "alice".to_string(),
+        };
+        let signature = key.sign(&canonical_content_bytes(&receipt));
+        use base64::Engine;
+        receipt.signature = base64::engine::general_purpose::STANDARD.encode(signature.to_bytes());
+        receipt
+    }
+
+    #[test]
+    fn test_verify_chain_passes_for_valid_chain() {
+        let key = SigningKey::from_bytes(&[3u8; 32]);
+        let r0 = signed_receipt(&key, "r0", GENESIS_PREV_HASH, 0);
+        let h0 = content_hash(&r0);
+        let r1 = signed_receipt(&key, "r1", &h0, 1);
+
+        let mut keys = HashMap::new();
+        keys.insert("alice".to_string(), key.verifying_key());
+
+        let result = verify_chain(&[r1, r0], &keys);
+        assert_eq!(result.verdict, "PASS");
+        assert!(result.errors.is_empty());
+    }
+
+    #[test]
+    fn test_verify_chain_flags_broken_link() {
+        let key = SigningKey::from_bytes(&[3u8; 32]);
+        let r0 = signed_receipt(&key, "r0", GENESIS_PREV_HASH, 0);
+        let r1 = signed_receipt(&key, "r1", "deadbeef", 1);
+
+        let mut keys = HashMap::new();
+        keys.insert("alice".to_string(), key.verifying_key());
+
+        let result = verify_chain(&[r0, r1], &keys);
+        assert_eq!(result.verdict, "FAIL");
+        assert_eq!(result.checks.get("chain_linkage").unwrap(), "fail");
+    }
+}
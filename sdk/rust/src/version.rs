@@ -0,0 +1,130 @@
+// HELM SDK — protocol version negotiation and capability gating.
+//
+// A client built against a newer or older kernel can silently send
+// requests that get rejected with a confusing ERROR_INTERNAL. `negotiate`
+// fetches `/version` up front, compares major versions against
+// `SUPPORTED_API_VERSION`, and records which capabilities the server
+// advertises so version-gated methods fail with an actionable
+// `HelmApiError::VersionMismatch` instead of an opaque HTTP error.
+
+use std::collections::HashSet;
+
+use crate::{HelmApiError, VersionInfo};
+
+/// Major.minor.patch of the HELM protocol this SDK was built against.
+pub const SUPPORTED_API_VERSION: &str = "1.0.0";
+
+/// SDK capabilities that are gated on the kernel's advertised version.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Capability {
+    Streaming,
+    EvidenceExport,
+    Conformance,
+}
+
+const MIN_STREAMING: (u64, u64, u64) = (1, 1, 0);
+const MIN_EVIDENCE_EXPORT: (u64, u64, u64) = (1, 0, 0);
+const MIN_CONFORMANCE: (u64, u64, u64) = (1, 0, 0);
+
+fn parse_semver(version: &str) -> Option<(u64, u64, u64)> {
+    let core = version.split(['-', '+']).next().unwrap_or(version);
+    let mut parts = core.split('.');
+    let major = parts.next()?.parse().ok()?;
+    let minor = parts.next().unwrap_or("0").parse().ok()?;
+    let patch = parts.next().unwrap_or("0").parse().ok()?;
+    Some((major, minor, patch))
+}
+
+/// Result of negotiating protocol capabilities with the kernel.
+#[derive(Debug, Clone)]
+pub struct Negotiated {
+    pub server_version: VersionInfo,
+    capabilities: HashSet<Capability>,
+}
+
+impl Negotiated {
+    pub(crate) fn from_version_info(server_version: VersionInfo) -> Result<Self, HelmApiError> {
+        let (client_major, _, _) = parse_semver(SUPPORTED_API_VERSION)
+            .expect("SUPPORTED_API_VERSION is a valid semver literal");
+        let server_semver = parse_semver(&server_version.version).ok_or_else(|| {
+            HelmApiError::VersionMismatch(format!(
+                "kernel reported an unparseable version: {:?}",
+                server_version.version
+            ))
+        })?;
+
+        if server_semver.0 != client_major {
+            return Err(HelmApiError::VersionMismatch(format!(
+                "kernel major version {} is incompatible with SDK version {}",
+                server_semver.0, SUPPORTED_API_VERSION
+            )));
+        }
+
+        let mut capabilities = HashSet::new();
+        if server_semver >= MIN_STREAMING {
+            capabilities.insert(Capability::Streaming);
+        }
+        if server_semver >= MIN_EVIDENCE_EXPORT {
+            capabilities.insert(Capability::EvidenceExport);
+        }
+        if server_semver >= MIN_CONFORMANCE {
+            capabilities.insert(Capability::Conformance);
+        }
+
+        Ok(Self {
+            server_version,
+            capabilities,
+        })
+    }
+
+    /// Whether the kernel advertised support for `capability`.
+    pub fn supports(&self, capability: Capability) -> bool {
+        self.capabilities.contains(&capability)
+    }
+
+    /// Error with [`HelmApiError::VersionMismatch`] unless `capability` is
+    /// supported.
+    pub fn require(&self, capability: Capability) -> Result<(), HelmApiError> {
+        if self.supports(capability) {
+            return Ok(());
+        }
+        Err(HelmApiError::VersionMismatch(format!(
+            "kernel {} does not advertise the {:?} capability",
+            self.server_version.version, capability
+        )))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn version_info(version: &str) -> VersionInfo {
+        VersionInfo {
+            version: version.to_string(),
+            commit: "abc123".to_string(),
+            build_time: "2026-01-01T00:00:00Z".to_string(),
+            go_version: "go1.22".to_string(),
+        }
+    }
+
+    #[test]
+    fn test_negotiate_matching_major_grants_capabilities() {
+        let negotiated = Negotiated::from_version_info(version_info("1.1.0")).unwrap();
+        assert!(negotiated.supports(Capability::Streaming));
+        assert!(negotiated.supports(Capability::EvidenceExport));
+    }
+
+    #[test]
+    fn test_negotiate_withholds_capability_below_threshold() {
+        let negotiated = Negotiated::from_version_info(version_info("1.0.0")).unwrap();
+        assert!(!negotiated.supports(Capability::Streaming));
+        assert!(negotiated.require(Capability::Streaming).is_err());
+    }
+
+    #[test]
+    fn test_negotiate_rejects_major_mismatch() {
+        let result = Negotiated::from_version_info(version_info("2.0.0"));
+        assert!(result.is_err());
+    }
+}